@@ -80,7 +80,13 @@ async fn main() -> anyhow::Result<()> {
     });
 
     while let Some(event) = event_receiver.recv().await {
-        tracing::info!("response: {event:#?}");
+        match event {
+            Ok(event) => tracing::info!("response: {event:#?}"),
+            Err(err) => {
+                tracing::error!("transport closed: {err}");
+                break;
+            }
+        }
     }
 
     Ok(())
@@ -0,0 +1,29 @@
+use jarust_interface::interface::janus_interface::JanusInterface;
+use jarust_interface::prelude::JaTransportResult;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy)]
+pub struct CreateConnectionParams {
+    pub ka_interval: u64,
+    pub timeout: Duration,
+}
+
+/// Thin wrapper around a transport-level [`JanusInterface`] that hands out
+/// sessions. Generic over the transport so both the RESTful and WebSocket
+/// implementors share this call site without duplicating it per-transport.
+#[derive(Debug, Clone)]
+pub struct JaConnection<I: JanusInterface> {
+    interface: I,
+}
+
+impl<I: JanusInterface> JaConnection<I> {
+    pub fn new(interface: I) -> Self {
+        Self { interface }
+    }
+
+    pub async fn create_session(&mut self, params: CreateConnectionParams) -> JaTransportResult<u64> {
+        self.interface
+            .create(params.ka_interval, params.timeout)
+            .await
+    }
+}
@@ -0,0 +1,208 @@
+use crate::error::JaError;
+use crate::japlugin::JaHandle;
+use crate::prelude::JaResult;
+use jarust_interface::japrotocol::EstablishmentProtocol;
+use jarust_interface::japrotocol::JaResponse;
+use rand::Rng;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::json;
+use serde_json::Value;
+use std::time::Duration;
+
+pub const PLUGIN_ID: &str = "janus.plugin.videoroom";
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PublisherType {
+    Publisher,
+    Subscriber,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RoomCreatedRsp {
+    pub room: u64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RoomDestroyedRsp {
+    pub room: u64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RoomExistsRsp {
+    pub room: u64,
+    pub exists: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RoomListRsp {
+    pub list: Vec<u64>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct JoinedRsp {
+    pub room: u64,
+    #[serde(default)]
+    pub publishers: Vec<PublisherInfo>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PublisherInfo {
+    pub id: u64,
+    #[serde(default)]
+    pub display: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub enum VideoRoomEvent {
+    Joined(JoinedRsp),
+    Event { room: u64, jsep: Option<Value> },
+}
+
+/// A typed handle for the Janus VideoRoom plugin, built on top of the
+/// lower-level generic plugin handle returned by `Session::attach`.
+#[derive(Debug, Clone)]
+pub struct VideoRoomHandle {
+    handle: JaHandle,
+}
+
+impl From<JaHandle> for VideoRoomHandle {
+    fn from(handle: JaHandle) -> Self {
+        Self { handle }
+    }
+}
+
+fn plugin_data(response: &JaResponse) -> JaResult<Value> {
+    serde_json::to_value(response)?
+        .get("plugindata")
+        .and_then(|plugindata| plugindata.get("data"))
+        .cloned()
+        .ok_or(JaError::IncompletePacket)
+}
+
+fn parse_plugin_data<T: serde::de::DeserializeOwned>(response: &JaResponse) -> JaResult<T> {
+    Ok(serde_json::from_value(plugin_data(response)?)?)
+}
+
+impl VideoRoomHandle {
+    pub async fn create_room(
+        &self,
+        room: Option<u64>,
+        timeout: Duration,
+    ) -> JaResult<RoomCreatedRsp> {
+        let mut body = json!({ "request": "create" });
+        if let Some(room) = room {
+            body["room"] = room.into();
+        }
+        let response = self.handle.send_waiton_rsp(body, timeout).await?;
+        parse_plugin_data(&response)
+    }
+
+    pub async fn destroy_room(&self, room: u64, timeout: Duration) -> JaResult<RoomDestroyedRsp> {
+        let body = json!({
+            "request": "destroy",
+            "room": room,
+        });
+        let response = self.handle.send_waiton_rsp(body, timeout).await?;
+        parse_plugin_data(&response)
+    }
+
+    pub async fn exists(&self, room: u64, timeout: Duration) -> JaResult<RoomExistsRsp> {
+        let body = json!({
+            "request": "exists",
+            "room": room,
+        });
+        let response = self.handle.send_waiton_rsp(body, timeout).await?;
+        parse_plugin_data(&response)
+    }
+
+    pub async fn list_rooms(&self, timeout: Duration) -> JaResult<RoomListRsp> {
+        let body = json!({
+            "request": "list",
+        });
+        let response = self.handle.send_waiton_rsp(body, timeout).await?;
+        parse_plugin_data(&response)
+    }
+
+    pub async fn join(
+        &self,
+        room: u64,
+        ptype: PublisherType,
+        id: Option<u32>,
+        timeout: Duration,
+    ) -> JaResult<()> {
+        let id = id.unwrap_or_else(|| rand::thread_rng().gen());
+        let body = json!({
+            "request": "join",
+            "ptype": ptype,
+            "room": room,
+            "id": id,
+        });
+        self.handle.send_waiton_ack(body, timeout).await?;
+        Ok(())
+    }
+
+    pub async fn configure(&self, mut body: Value, jsep: EstablishmentProtocol) -> JaResult<()> {
+        body["request"] = "configure".into();
+        self.handle.fire_and_forget_with_est(body, jsep).await?;
+        Ok(())
+    }
+
+    pub async fn publish(&self, mut body: Value, jsep: EstablishmentProtocol) -> JaResult<()> {
+        body["request"] = "publish".into();
+        self.handle.fire_and_forget_with_est(body, jsep).await?;
+        Ok(())
+    }
+
+    pub async fn unpublish(&self, timeout: Duration) -> JaResult<()> {
+        let body = json!({ "request": "unpublish" });
+        self.handle.send_waiton_ack(body, timeout).await?;
+        Ok(())
+    }
+
+    pub async fn start(
+        &self,
+        room: u64,
+        jsep: EstablishmentProtocol,
+        timeout: Duration,
+    ) -> JaResult<()> {
+        let body = json!({
+            "request": "start",
+            "room": room,
+        });
+        self.handle
+            .send_waiton_ack_with_est(body, jsep, timeout)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn leave(&self, timeout: Duration) -> JaResult<()> {
+        let body = json!({ "request": "leave" });
+        self.handle.send_waiton_ack(body, timeout).await?;
+        Ok(())
+    }
+}
+
+/// Parses an async `joined`/`event` notification arriving on the handle's
+/// event receiver into a typed [`VideoRoomEvent`].
+pub fn parse_videoroom_event(response: &JaResponse) -> JaResult<Option<VideoRoomEvent>> {
+    let data = plugin_data(response)?;
+    let event = match data.get("videoroom").and_then(Value::as_str) {
+        Some("joined") => Some(VideoRoomEvent::Joined(serde_json::from_value(data)?)),
+        Some("event") => {
+            data.get("room")
+                .and_then(Value::as_u64)
+                .map(|room| VideoRoomEvent::Event {
+                    room,
+                    jsep: response_jsep(response),
+                })
+        }
+        _ => None,
+    };
+    Ok(event)
+}
+
+fn response_jsep(response: &JaResponse) -> Option<Value> {
+    serde_json::to_value(response).ok()?.get("jsep").cloned()
+}
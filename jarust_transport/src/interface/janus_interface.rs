@@ -0,0 +1,66 @@
+use crate::handle_msg::HandleMessage;
+use crate::handle_msg::HandleMessageWithEstablishment;
+use crate::handle_msg::HandleMessageWithEstablishmentAndTimeout;
+use crate::handle_msg::HandleMessageWithTimeout;
+use crate::japrotocol::JaResponse;
+use crate::prelude::JaTransportResult;
+use crate::respones::ServerInfoRsp;
+use crate::transaction_gen::GenerateTransaction;
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+#[derive(Debug, Clone)]
+pub struct ConnectionParams {
+    pub url: String,
+    pub apisecret: Option<String>,
+    pub namespace: String,
+    pub capacity: usize,
+}
+
+#[async_trait::async_trait]
+pub trait JanusInterface: Clone + Send + Sync + Sized + 'static {
+    async fn make_interface(
+        conn_params: ConnectionParams,
+        transaction_generator: impl GenerateTransaction,
+    ) -> JaTransportResult<Self>;
+
+    /// Creates a new Janus session. `ka_interval` is the number of seconds
+    /// between keep-alive pings kept for the lifetime of the session; pass
+    /// `0` to disable the keep-alive task.
+    async fn create(&self, ka_interval: u64, timeout: Duration) -> JaTransportResult<u64>;
+
+    async fn server_info(&self, timeout: Duration) -> JaTransportResult<ServerInfoRsp>;
+
+    async fn attach(
+        &self,
+        session_id: u64,
+        plugin_id: String,
+        timeout: Duration,
+    ) -> JaTransportResult<(u64, mpsc::UnboundedReceiver<JaTransportResult<JaResponse>>)>;
+
+    async fn keep_alive(&self, session_id: u64, timeout: Duration) -> JaTransportResult<()>;
+
+    async fn destory(&self, session_id: u64, timeout: Duration) -> JaTransportResult<()>;
+
+    async fn fire_and_forget_msg(&self, message: HandleMessage) -> JaTransportResult<()>;
+
+    async fn send_msg_waiton_ack(
+        &self,
+        message: HandleMessageWithTimeout,
+    ) -> JaTransportResult<JaResponse>;
+
+    async fn internal_send_msg_waiton_rsp(
+        &self,
+        message: HandleMessageWithTimeout,
+    ) -> JaTransportResult<JaResponse>;
+
+    async fn fire_and_forget_msg_with_est(
+        &self,
+        message: HandleMessageWithEstablishment,
+    ) -> JaTransportResult<()>;
+
+    async fn send_msg_waiton_ack_with_est(
+        &self,
+        message: HandleMessageWithEstablishmentAndTimeout,
+    ) -> JaTransportResult<JaResponse>;
+}
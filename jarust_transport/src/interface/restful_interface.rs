@@ -12,17 +12,20 @@ use crate::japrotocol::ResponseType;
 use crate::napmap::NapMap;
 use crate::prelude::JaTransportResult;
 use crate::respones::ServerInfoRsp;
-use crate::router::Router;
 use crate::transaction_gen::GenerateTransaction;
 use crate::transaction_gen::TransactionGenerator;
-use crate::transaction_manager::TransactionManager;
 use jarust_rt::JaTask;
 use serde_json::json;
 use serde_json::Value;
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::mpsc;
 use tokio::sync::Mutex;
+use tokio_util::sync::CancellationToken;
+
+const MIN_POLL_BACKOFF: Duration = Duration::from_millis(100);
+const MAX_POLL_BACKOFF: Duration = Duration::from_secs(5);
 
 #[derive(Debug)]
 struct Shared {
@@ -37,8 +40,7 @@ struct Shared {
 #[derive(Debug)]
 struct Exclusive {
     tasks: Vec<JaTask>,
-    router: Router,
-    transaction_manager: TransactionManager,
+    poll_shutdown_signals: HashMap<u64, Vec<CancellationToken>>,
 }
 
 #[derive(Debug)]
@@ -65,6 +67,20 @@ impl RestfulInterface {
         request["transaction"] = transaction.clone().into();
         (request, transaction)
     }
+
+    fn ensure_ack(response: JaResponse) -> JaTransportResult<()> {
+        match response.janus {
+            ResponseType::Error { error } => {
+                let what = JaTransportError::JanusError {
+                    code: error.code,
+                    reason: error.reason,
+                };
+                tracing::error!("{what}");
+                Err(what)
+            }
+            _ => Ok(()),
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -75,8 +91,6 @@ impl JanusInterface for RestfulInterface {
     ) -> JaTransportResult<Self> {
         let client = reqwest::Client::new();
         let transaction_generator = TransactionGenerator::new(transaction_generator);
-        let transaction_manager = TransactionManager::new(conn_params.capacity);
-        let (router, _) = Router::new(&conn_params.namespace).await;
         let shared = Shared {
             namespace: conn_params.namespace,
             apisecret: conn_params.apisecret,
@@ -87,8 +101,7 @@ impl JanusInterface for RestfulInterface {
         };
         let exclusive = Exclusive {
             tasks: Vec::new(),
-            router,
-            transaction_manager,
+            poll_shutdown_signals: HashMap::new(),
         };
         let inner = InnerResultfulInterface {
             shared,
@@ -99,7 +112,7 @@ impl JanusInterface for RestfulInterface {
         })
     }
 
-    async fn create(&self, timeout: Duration) -> JaTransportResult<u64> {
+    async fn create(&self, ka_interval: u64, timeout: Duration) -> JaTransportResult<u64> {
         let baseurl = &self.inner.shared.baseurl;
         let request = json!({"janus": "create"});
         let (request, _) = self.decorate_request(request);
@@ -131,6 +144,38 @@ impl JanusInterface for RestfulInterface {
                 return Err(JaTransportError::UnexpectedResponse);
             }
         };
+
+        if ka_interval > 0 {
+            let keep_alive_task = jarust_rt::spawn({
+                let weak_inner = Arc::downgrade(&self.inner);
+                async move {
+                    let mut interval = tokio::time::interval(Duration::from_secs(ka_interval));
+                    interval.tick().await;
+                    loop {
+                        interval.tick().await;
+                        let Some(inner) = weak_inner.upgrade() else {
+                            tracing::debug!(
+                                "interface for session {session_id} dropped, stopping keep-alive"
+                            );
+                            break;
+                        };
+                        let interface = RestfulInterface { inner };
+                        if let Err(err) = interface.keep_alive(session_id, timeout).await {
+                            tracing::error!("keep-alive failed for session {session_id}: {err}");
+                        }
+                    }
+                }
+            });
+            self.inner
+                .exclusive
+                .lock()
+                .await
+                .tasks
+                .push(keep_alive_task);
+        } else {
+            tracing::debug!("ka_interval is 0 for session {session_id}, skipping keep-alive task");
+        }
+
         Ok(session_id)
     }
 
@@ -161,7 +206,7 @@ impl JanusInterface for RestfulInterface {
         session_id: u64,
         plugin_id: String,
         timeout: Duration,
-    ) -> JaTransportResult<(u64, mpsc::UnboundedReceiver<JaResponse>)> {
+    ) -> JaTransportResult<(u64, mpsc::UnboundedReceiver<JaTransportResult<JaResponse>>)> {
         let baseurl = &self.inner.shared.baseurl;
         let request = json!({
             "janus": "attach",
@@ -196,34 +241,122 @@ impl JanusInterface for RestfulInterface {
             }
         };
         let (tx, rx) = mpsc::unbounded_channel();
+        let shutdown = CancellationToken::new();
 
         let handle = jarust_rt::spawn({
             let client = self.inner.shared.client.clone();
             let baseurl = baseurl.clone();
+            let shutdown = shutdown.clone();
+            let weak_inner = Arc::downgrade(&self.inner);
 
             async move {
+                let mut backoff = MIN_POLL_BACKOFF;
                 loop {
-                    if let Ok(response) = client
+                    let Some(inner) = weak_inner.upgrade() else {
+                        tracing::debug!(
+                            "interface for session {session_id} dropped, stopping long-poll pump"
+                        );
+                        break;
+                    };
+                    let poll = client
                         .get(format!("{baseurl}/janus/{session_id}?maxev=5"))
-                        .send()
-                        .await
-                    {
-                        if let Ok(res) = response.json::<Vec<JaResponse>>().await {
-                            for r in res {
-                                let _ = tx.send(r);
+                        .send();
+
+                    tokio::select! {
+                        _ = shutdown.cancelled() => {
+                            tracing::debug!("stopping long-poll pump for session {session_id}");
+                            break;
+                        }
+                        result = poll => {
+                            let responses = match result {
+                                Ok(response) if !response.status().is_success() => {
+                                    let what = JaTransportError::TransportClosed(format!(
+                                        "long-poll for session {session_id} closed with status {}",
+                                        response.status()
+                                    ));
+                                    tracing::error!("{what}");
+                                    let _ = tx.send(Err(what));
+                                    break;
+                                }
+                                Ok(response) => response.json::<Vec<JaResponse>>().await,
+                                Err(err) => Err(err),
+                            };
+                            match responses {
+                                Ok(responses) => {
+                                    backoff = MIN_POLL_BACKOFF;
+                                    let mut receiver_dropped = false;
+                                    for response in responses {
+                                        if let Some(transaction) = response.transaction.clone() {
+                                            inner.shared.rsp_map.insert(transaction, response.clone()).await;
+                                        }
+                                        if tx.send(Ok(response)).is_err() {
+                                            receiver_dropped = true;
+                                        }
+                                    }
+                                    if receiver_dropped {
+                                        tracing::debug!(
+                                            "event receiver dropped for session {session_id}, stopping long-poll pump"
+                                        );
+                                        break;
+                                    }
+                                }
+                                Err(err) => {
+                                    tracing::error!(
+                                        "long-poll request failed for session {session_id}: {err}"
+                                    );
+                                    let terminal = err.is_connect() || backoff >= MAX_POLL_BACKOFF;
+                                    if terminal {
+                                        let what = JaTransportError::TransportClosed(format!(
+                                            "long-poll for session {session_id} failed: {err}"
+                                        ));
+                                        tracing::error!("{what}");
+                                        let _ = tx.send(Err(what));
+                                        break;
+                                    }
+                                    tokio::select! {
+                                        _ = shutdown.cancelled() => {
+                                            tracing::debug!(
+                                                "stopping long-poll pump for session {session_id}"
+                                            );
+                                            break;
+                                        }
+                                        _ = tokio::time::sleep(backoff) => {
+                                            backoff = (backoff * 2).min(MAX_POLL_BACKOFF);
+                                        }
+                                    }
+                                }
                             }
                         }
-                    };
+                    }
                 }
             }
         });
 
-        self.inner.exclusive.lock().await.tasks.push(handle);
+        let mut exclusive = self.inner.exclusive.lock().await;
+        exclusive
+            .poll_shutdown_signals
+            .entry(session_id)
+            .or_default()
+            .push(shutdown);
+        exclusive.tasks.push(handle);
+        drop(exclusive);
 
         Ok((handle_id, rx))
     }
 
-    async fn keep_alive(&self, _: u64, _: Duration) -> JaTransportResult<()> {
+    async fn keep_alive(&self, session_id: u64, timeout: Duration) -> JaTransportResult<()> {
+        let baseurl = &self.inner.shared.baseurl;
+        let request = json!({"janus": "keepalive"});
+        let (request, _) = self.decorate_request(request);
+
+        self.inner
+            .shared
+            .client
+            .post(format!("{baseurl}/janus/{session_id}"))
+            .json(&request)
+            .timeout(timeout)
+            .send()
+            .await?;
         Ok(())
     }
 
@@ -234,15 +367,31 @@ impl JanusInterface for RestfulInterface {
         });
         let (request, _) = self.decorate_request(request);
 
-        self.inner
+        if let Some(shutdowns) = self
+            .inner
+            .exclusive
+            .lock()
+            .await
+            .poll_shutdown_signals
+            .remove(&session_id)
+        {
+            for shutdown in shutdowns {
+                shutdown.cancel();
+            }
+        }
+
+        let response = self
+            .inner
             .shared
             .client
             .post(format!("{baseurl}/janus/{session_id}"))
             .json(&request)
             .timeout(timeout)
             .send()
+            .await?
+            .json::<JaResponse>()
             .await?;
-        Ok(())
+        Self::ensure_ack(response)
     }
 
     async fn fire_and_forget_msg(&self, message: HandleMessage) -> JaTransportResult<()> {
@@ -255,14 +404,17 @@ impl JanusInterface for RestfulInterface {
             "body": message.body
         });
         let (request, _) = self.decorate_request(request);
-        self.inner
+        let response = self
+            .inner
             .shared
             .client
             .post(format!("{baseurl}/janus/{session_id}/{handle_id}"))
             .json(&request)
             .send()
+            .await?
+            .json::<JaResponse>()
             .await?;
-        Ok(())
+        Self::ensure_ack(response)
     }
 
     async fn send_msg_waiton_ack(
@@ -304,7 +456,12 @@ impl JanusInterface for RestfulInterface {
             "janus": "message",
             "body": message.body
         });
-        let (request, _) = self.decorate_request(request);
+        let (request, transaction) = self.decorate_request(request);
+
+        // Janus answers some plugin requests (e.g. videoroom create/destroy/
+        // exists/list) synchronously in the POST response itself; others only
+        // ack here and deliver the real result later on the long-poll pump,
+        // routed into `rsp_map` by the matching transaction id.
         let response = self
             .inner
             .shared
@@ -316,7 +473,17 @@ impl JanusInterface for RestfulInterface {
             .await?
             .json::<JaResponse>()
             .await?;
-        Ok(response)
+
+        match response.janus {
+            ResponseType::Ack => {
+                self.inner
+                    .shared
+                    .rsp_map
+                    .get(&transaction, message.timeout)
+                    .await
+            }
+            _ => Ok(response),
+        }
     }
 
     async fn fire_and_forget_msg_with_est(
@@ -340,14 +507,17 @@ impl JanusInterface for RestfulInterface {
             }
         };
         let (request, _) = self.decorate_request(request);
-        self.inner
+        let response = self
+            .inner
             .shared
             .client
             .post(format!("{baseurl}/janus/{session_id}/{handle_id}"))
             .json(&request)
             .send()
+            .await?
+            .json::<JaResponse>()
             .await?;
-        Ok(())
+        Self::ensure_ack(response)
     }
 
     async fn send_msg_waiton_ack_with_est(
@@ -387,6 +557,9 @@ impl JanusInterface for RestfulInterface {
 
 impl Drop for Exclusive {
     fn drop(&mut self) {
+        for shutdown in self.poll_shutdown_signals.drain().flat_map(|(_, s)| s) {
+            shutdown.cancel();
+        }
         for task in self.tasks.drain(..) {
             task.cancel();
         }